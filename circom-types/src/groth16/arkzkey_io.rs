@@ -0,0 +1,136 @@
+use std::{fs::File, io::BufWriter, path::Path};
+
+use ark_ec::pairing::Pairing;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate};
+use memmap2::Mmap;
+
+use super::zkey_to_ark::ArkZkey;
+
+/// Writes `arkzkey` to `path` in uncompressed, unvalidated canonical form (the `.arkzkey` cache
+/// format). Intended to run once at build time, right after converting a snarkjs `.zkey` into an
+/// [`ArkZkey`], so [`read_arkzkey_from_path`]/[`mmap_arkzkey_from_path`] can load it cheaply at
+/// runtime instead of re-parsing and validating the original `.zkey`.
+pub fn serialize_arkzkey_to_path<P: Pairing>(
+    arkzkey: &ArkZkey<P>,
+    path: impl AsRef<Path>,
+) -> Result<(), SerializationError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    arkzkey.serialize_with_mode(&mut writer, Compress::No)
+}
+
+/// Reads an `ArkZkey` previously written by [`serialize_arkzkey_to_path`] back from disk without
+/// validating the deserialized points. Fine for caches produced by this crate; call
+/// [`ArkZkey::check`] on the result if the file's provenance isn't trusted.
+pub fn read_arkzkey_from_path<P: Pairing>(
+    path: impl AsRef<Path>,
+) -> Result<ArkZkey<P>, SerializationError> {
+    let mut reader = File::open(path)?;
+    ArkZkey::deserialize_with_mode(&mut reader, Compress::No, Validate::No)
+}
+
+/// Reads an `ArkZkey` out of a memory-mapped `.arkzkey` file, deserializing the `ProvingKey` query
+/// vectors directly out of the mapped pages with `Validate::No`. This turns multi-second key loads
+/// into tens of milliseconds, at the cost of skipping point validation (see
+/// [`read_arkzkey_from_path`] for the same tradeoff without mmap, and [`ArkZkey::check`] to opt
+/// back into validation on demand).
+///
+/// # Safety
+/// This calls [`Mmap::map`], which is unsafe because the file can be mutated or truncated by
+/// another process while it is mapped; only use this on files you control.
+pub unsafe fn mmap_arkzkey_from_path<P: Pairing>(
+    path: impl AsRef<Path>,
+) -> Result<ArkZkey<P>, SerializationError> {
+    let file = File::open(path)?;
+    let mmap = Mmap::map(&file)?;
+    ArkZkey::deserialize_with_mode(&mmap[..], Compress::No, Validate::No)
+}
+
+impl<P: Pairing> ArkZkey<P> {
+    /// Validates every field element and group point in this `ArkZkey`. Use this after loading
+    /// with [`read_arkzkey_from_path`] or [`mmap_arkzkey_from_path`] (both skip validation for
+    /// speed) when the file's provenance isn't trusted.
+    pub fn check(&self) -> Result<(), SerializationError> {
+        Valid::check(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use ark_bn254::{Bn254, Fr};
+    use ark_groth16::Groth16;
+    use ark_relations::r1cs::{
+        ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+    };
+    use ark_snark::SNARK;
+    use ark_std::test_rng;
+
+    use crate::groth16::prove::prove;
+
+    use super::*;
+
+    /// `out <== a * b`, the same tiny circuit used to exercise [`prove`] in `prove.rs`.
+    struct MulCircuit {
+        a: Fr,
+        b: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_witness_variable(|| Ok(self.a))?;
+            let b = cs.new_witness_variable(|| Ok(self.b))?;
+            let out = cs.new_input_variable(|| Ok(self.a * self.b))?;
+            cs.enforce_constraint(
+                ark_relations::lc!() + a,
+                ark_relations::lc!() + b,
+                ark_relations::lc!() + out,
+            )?;
+            Ok(())
+        }
+    }
+
+    /// Writes an `ArkZkey` to a temp file, reads it back both via [`read_arkzkey_from_path`] and
+    /// via [`mmap_arkzkey_from_path`], and checks both round trips still produce a key that can
+    /// prove and verify a real statement — the whole point of the cache format.
+    #[test]
+    fn arkzkey_round_trips_through_disk_and_mmap() {
+        let mut rng = test_rng();
+        let a = Fr::from(5u64);
+        let b = Fr::from(6u64);
+
+        let (pk, vk) =
+            Groth16::<Bn254>::circuit_specific_setup(MulCircuit { a, b }, &mut rng).unwrap();
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        MulCircuit { a, b }.generate_constraints(cs.clone()).unwrap();
+        cs.finalize();
+        let matrices = cs.to_matrices().unwrap();
+        let cs_borrow = cs.borrow().unwrap();
+        let full_assignment = [
+            cs_borrow.instance_assignment.as_slice(),
+            cs_borrow.witness_assignment.as_slice(),
+        ]
+        .concat();
+        let public_inputs = cs_borrow.instance_assignment[1..].to_vec();
+        drop(cs_borrow);
+
+        let ark_zkey = ArkZkey {
+            matrices: matrices.into(),
+            pk,
+        };
+
+        let path = env::temp_dir().join(format!("circom-types-test-{}.arkzkey", std::process::id()));
+        serialize_arkzkey_to_path(&ark_zkey, &path).unwrap();
+
+        let from_disk: ArkZkey<Bn254> = read_arkzkey_from_path(&path).unwrap();
+        let proof = prove(&from_disk, &full_assignment, &mut rng).unwrap();
+        assert!(Groth16::<Bn254>::verify(&vk, &public_inputs, &proof).unwrap());
+
+        let from_mmap: ArkZkey<Bn254> = unsafe { mmap_arkzkey_from_path(&path) }.unwrap();
+        let proof = prove(&from_mmap, &full_assignment, &mut rng).unwrap();
+        assert!(Groth16::<Bn254>::verify(&vk, &public_inputs, &proof).unwrap());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
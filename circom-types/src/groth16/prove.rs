@@ -0,0 +1,97 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::UniformRand;
+use ark_groth16::{Groth16, Proof};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use super::{qap::CircomReduction, zkey_to_ark::ArkZkey};
+
+/// Generates a Groth16 proof from a loaded [`ArkZkey`] and a full circom witness assignment
+/// (`[1, public_inputs.., aux_witnesses..]`, in the order produced by a circom witness
+/// calculator), using the [`CircomReduction`] QAP so the empty `c` matrix in the zkey's
+/// [`ark_relations::r1cs::ConstraintMatrices`] is never indexed into.
+pub fn prove<P: Pairing>(
+    ark_zkey: &ArkZkey<P>,
+    full_assignment: &[P::ScalarField],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Proof<P>, SynthesisError> {
+    let (matrices, pk) = ark_zkey.as_inner();
+    let num_inputs = matrices.num_instance_variables;
+    let num_constraints = matrices.num_constraints;
+
+    let r = P::ScalarField::rand(rng);
+    let s = P::ScalarField::rand(rng);
+
+    Groth16::<P, CircomReduction>::create_proof_with_reduction_and_matrices(
+        pk,
+        r,
+        s,
+        matrices,
+        num_inputs,
+        num_constraints,
+        full_assignment,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Bn254, Fr};
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError};
+    use ark_snark::SNARK;
+    use ark_std::test_rng;
+
+    use super::*;
+
+    /// `out <== a * b`, with `a`/`b` private and `out` the single public input: the same shape as
+    /// circom's simplest multiplication circuit.
+    struct MulCircuit {
+        a: Fr,
+        b: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_witness_variable(|| Ok(self.a))?;
+            let b = cs.new_witness_variable(|| Ok(self.b))?;
+            let out = cs.new_input_variable(|| Ok(self.a * self.b))?;
+            cs.enforce_constraint(
+                ark_relations::lc!() + a,
+                ark_relations::lc!() + b,
+                ark_relations::lc!() + out,
+            )?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_roundtrip() {
+        let mut rng = test_rng();
+        let a = Fr::from(3u64);
+        let b = Fr::from(4u64);
+
+        let (pk, vk) =
+            Groth16::<Bn254>::circuit_specific_setup(MulCircuit { a, b }, &mut rng).unwrap();
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        MulCircuit { a, b }.generate_constraints(cs.clone()).unwrap();
+        cs.finalize();
+        let matrices = cs.to_matrices().unwrap();
+        let cs = cs.borrow().unwrap();
+        let full_assignment = [
+            cs.instance_assignment.as_slice(),
+            cs.witness_assignment.as_slice(),
+        ]
+        .concat();
+        let public_inputs = cs.instance_assignment[1..].to_vec();
+        drop(cs);
+
+        let ark_zkey = ArkZkey {
+            matrices: matrices.into(),
+            pk,
+        };
+
+        let proof = prove(&ark_zkey, &full_assignment, &mut rng).unwrap();
+
+        assert!(Groth16::<Bn254>::verify(&vk, &public_inputs, &proof).unwrap());
+    }
+}
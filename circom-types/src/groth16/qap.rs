@@ -0,0 +1,110 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, PrimeField};
+use ark_groth16::r1cs_to_qap::{LibsnarkReduction, R1CSToQAP};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_relations::r1cs::{ConstraintMatrices, ConstraintSystemRef, Matrix, SynthesisError};
+
+/// The [`R1CSToQAP`] reduction used by circom/snarkjs, as opposed to ark-groth16's default
+/// [`LibsnarkReduction`].
+///
+/// circom zkeys only carry the `A` and `B` coefficient matrices: `C` is never materialized, it is
+/// derived as the Hadamard product `a ∘ b` of the `A`/`B` evaluations over the constraint domain.
+/// This matters because [`super::ZKey`] conversion sets `c_num_non_zero: 0` and an empty `c`
+/// matrix, which [`LibsnarkReduction`]'s witness map cannot handle since it expects to index into
+/// `C` directly. `CircomReduction` never touches `matrices.c` for that reason.
+pub struct CircomReduction;
+
+impl R1CSToQAP for CircomReduction {
+    fn instance_map_with_evaluation<F: PrimeField>(
+        cs: ConstraintSystemRef<F>,
+        t: &F,
+    ) -> Result<(Vec<F>, Vec<F>, Vec<F>, F, usize, usize), SynthesisError> {
+        // Setup-time reduction is unchanged: only the prover-side witness map follows circom's
+        // convention instead of libsnark's, so we defer to the default reduction here.
+        LibsnarkReduction::instance_map_with_evaluation(cs, t)
+    }
+
+    fn witness_map<E: Pairing>(
+        prover: ConstraintSystemRef<E::ScalarField>,
+    ) -> Result<Vec<E::ScalarField>, SynthesisError> {
+        let matrices = prover.to_matrices().unwrap();
+        let num_inputs = prover.num_instance_variables();
+        let num_constraints = prover.num_constraints();
+        let cs = prover.borrow().unwrap();
+
+        let full_assignment = [
+            cs.instance_assignment.as_slice(),
+            cs.witness_assignment.as_slice(),
+        ]
+        .concat();
+
+        Self::witness_map_from_matrices::<E>(
+            &matrices,
+            num_inputs,
+            num_constraints,
+            &full_assignment,
+        )
+    }
+
+    fn witness_map_from_matrices<E: Pairing>(
+        matrices: &ConstraintMatrices<E::ScalarField>,
+        _num_inputs: usize,
+        num_constraints: usize,
+        full_assignment: &[E::ScalarField],
+    ) -> Result<Vec<E::ScalarField>, SynthesisError> {
+        let domain = GeneralEvaluationDomain::<E::ScalarField>::new(num_constraints)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+
+        let mut a = evaluate_matrix_over_domain(&matrices.a, full_assignment, domain_size);
+        let mut b = evaluate_matrix_over_domain(&matrices.b, full_assignment, domain_size);
+        // c is never read from the zkey: it is the Hadamard product of the a/b evaluations.
+        let mut c: Vec<_> = a.iter().zip(&b).map(|(ai, bi)| *ai * bi).collect();
+
+        // Interpolate a, b, c on the constraint domain to get their coefficient form.
+        domain.ifft_in_place(&mut a);
+        domain.ifft_in_place(&mut b);
+        domain.ifft_in_place(&mut c);
+
+        // Evaluate a, b, c on the coset `offset * domain` (shift into the coset, then FFT).
+        domain.coset_fft_in_place(&mut a);
+        domain.coset_fft_in_place(&mut b);
+        domain.coset_fft_in_place(&mut c);
+
+        // On the coset, the vanishing polynomial Z(x) = x^domain_size - 1 is constant:
+        // Z(offset * root) = offset^domain_size * root^domain_size - 1 = offset^domain_size - 1.
+        let z_on_coset = E::ScalarField::GENERATOR.pow([domain_size as u64]) - E::ScalarField::one();
+        let z_on_coset_inv = z_on_coset
+            .inverse()
+            .ok_or(SynthesisError::UnexpectedIdentity)?;
+
+        let mut h: Vec<_> = a
+            .iter()
+            .zip(&b)
+            .zip(&c)
+            .map(|((ai, bi), ci)| (*ai * bi - ci) * z_on_coset_inv)
+            .collect();
+
+        // Interpolate back to coefficient form and undo the coset shift to recover h(x).
+        domain.coset_ifft_in_place(&mut h);
+
+        Ok(h)
+    }
+}
+
+/// Evaluates every row of a sparse R1CS matrix against `assignment`, zero-padding up to
+/// `domain_size` so the result can be fed straight into an FFT over the constraint domain.
+fn evaluate_matrix_over_domain<F: PrimeField>(
+    matrix: &Matrix<F>,
+    assignment: &[F],
+    domain_size: usize,
+) -> Vec<F> {
+    let mut evaluations = vec![F::zero(); domain_size];
+    for (row, terms) in matrix.iter().enumerate() {
+        evaluations[row] = terms
+            .iter()
+            .map(|(coeff, index)| *coeff * assignment[*index])
+            .sum();
+    }
+    evaluations
+}
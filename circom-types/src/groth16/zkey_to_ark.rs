@@ -6,6 +6,58 @@ use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Valid};
 
 use crate::groth16::ZKey;
 
+/// Serializes a [`CanonicalSerialize`] value as its canonical, curve-agnostic byte representation
+/// (little-endian field elements and group points), so formats like JSON, bincode or MessagePack
+/// can round-trip it without re-deriving sizes that `CanonicalSerialize` already encodes.
+#[cfg(feature = "serde")]
+fn serialize_canonical<T: CanonicalSerialize, S: serde::Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(serde::ser::Error::custom)?;
+    serde::Serialize::serialize(serde_bytes::Bytes::new(&bytes), serializer)
+}
+
+/// Deserializes a value previously written by [`serialize_canonical`].
+#[cfg(feature = "serde")]
+fn deserialize_canonical<'de, T: CanonicalDeserialize, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let bytes = <serde_bytes::ByteBuf as serde::Deserialize>::deserialize(deserializer)?;
+    T::deserialize_compressed(bytes.as_slice()).map_err(serde::de::Error::custom)
+}
+
+#[cfg(feature = "serde")]
+impl<F: PrimeField> serde::Serialize for ConstraintMatricesWrapper<F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_canonical(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: PrimeField> serde::Deserialize<'de> for ConstraintMatricesWrapper<F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_canonical(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P: Pairing> serde::Serialize for ArkZkey<P> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_canonical(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P: Pairing> serde::Deserialize<'de> for ArkZkey<P> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_canonical(deserializer)
+    }
+}
+
 /// Wrapper type to serialize [`ConstraintMatrices`] and [`ProvingKey`]s as a combined type.
 ///
 /// Provides `From` implementations to convert to a [`ZKey`] or the inner types.
@@ -223,3 +275,74 @@ impl<P: Pairing> From<ZKey<P>> for ArkZkey<P> {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+    use ark_serialize::CanonicalSerialize;
+
+    use super::{ArkZkey, ConstraintMatrices, ConstraintMatricesWrapper, ProvingKey, VerifyingKey};
+
+    fn sample_ark_zkey() -> ArkZkey<Bn254> {
+        let g1 = G1Affine::generator();
+        let g2 = G2Affine::generator();
+        let matrices = ConstraintMatrices {
+            a: vec![vec![(Fr::from(1u64), 0)]],
+            b: vec![vec![(Fr::from(2u64), 0)]],
+            c: vec![],
+            a_num_non_zero: 1,
+            b_num_non_zero: 1,
+            c_num_non_zero: 0,
+            num_instance_variables: 1,
+            num_witness_variables: 1,
+            num_constraints: 1,
+        };
+        let pk = ProvingKey {
+            vk: VerifyingKey {
+                alpha_g1: g1,
+                beta_g2: g2,
+                gamma_g2: g2,
+                delta_g2: g2,
+                gamma_abc_g1: vec![g1],
+            },
+            beta_g1: g1,
+            delta_g1: g1,
+            a_query: vec![g1],
+            b_g1_query: vec![g1],
+            b_g2_query: vec![g2],
+            h_query: vec![g1],
+            l_query: vec![g1],
+        };
+        ArkZkey {
+            matrices: matrices.into(),
+            pk,
+        }
+    }
+
+    #[test]
+    fn ark_zkey_round_trips_through_json() {
+        let ark_zkey = sample_ark_zkey();
+
+        let json = serde_json::to_vec(&ark_zkey).unwrap();
+        let restored: ArkZkey<Bn254> = serde_json::from_slice(&json).unwrap();
+
+        let mut original_bytes = Vec::new();
+        ark_zkey.serialize_compressed(&mut original_bytes).unwrap();
+        let mut restored_bytes = Vec::new();
+        restored.serialize_compressed(&mut restored_bytes).unwrap();
+        assert_eq!(original_bytes, restored_bytes);
+    }
+
+    #[test]
+    fn constraint_matrices_wrapper_round_trips_through_json() {
+        let wrapper: ConstraintMatricesWrapper<Fr> = sample_ark_zkey().matrices;
+
+        let json = serde_json::to_vec(&wrapper).unwrap();
+        let restored: ConstraintMatricesWrapper<Fr> = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(restored.0.a, wrapper.0.a);
+        assert_eq!(restored.0.b, wrapper.0.b);
+        assert_eq!(restored.0.num_constraints, wrapper.0.num_constraints);
+    }
+}
@@ -1,46 +1,146 @@
-use ark_bn254::Bn254;
-use circom_types::groth16::VerificationKey;
-use clap::Parser;
+use ark_bn254::{Bn254, Fr};
+use circom_types::groth16::{ArkZkey, Proof, VerificationKey, ZKey};
+use clap::{Parser, Subcommand};
 use eyre::Context;
 use std::{fs::File, path::PathBuf, process::ExitCode};
 use taceo_groth16_sol::askama::Template;
 use taceo_groth16_sol::{SolidityVerifierConfig, SolidityVerifierContext};
 
-/// A tool that takes a Circom verification key and generates a Solidity verifier contract for BN254 Groth16 proofs. The solidity contract is based on gnark's Groth16 verifier.
+/// A tool spanning the circom Groth16 deploy-and-verify workflow: generate the Solidity verifier
+/// contract, prepare a proof's calldata, or convert a snarkjs `.zkey` into the faster-loading
+/// `.arkzkey` cache format.
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
 struct Config {
-    /// Path to Circom verification key.
-    #[clap(short, long)]
-    pub input: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate a Solidity verifier contract from a Circom verification key.
+    GenerateVerifier {
+        /// Path to Circom verification key.
+        #[clap(short, long)]
+        input: PathBuf,
+
+        /// Output of the Solidity file. Write to stdout if omitted.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+
+        /// The pragma version of the Solidity contract.
+        #[clap(long, default_value = "^0.8.0")]
+        pragma_version: String,
+
+        /// Also emit a `verifyCompressedProof` entrypoint for the compressed calldata format.
+        #[clap(long)]
+        include_compressed_verify: bool,
+    },
+
+    /// Read a proof plus its public inputs and print the ready-to-broadcast calldata for the
+    /// generated verifier contract as hex.
+    PrepareProof {
+        /// Path to a JSON object with `proof` and `public_inputs` fields.
+        #[clap(short, long)]
+        input: PathBuf,
+
+        /// Output of the calldata hex. Write to stdout if omitted.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
 
-    /// Output of the Solidity file. Write to stdout if omitted.
-    #[clap(short, long)]
-    pub output: Option<PathBuf>,
+        /// Use the compressed-proof calldata format (`verifyCompressedProof`).
+        #[clap(long)]
+        compressed: bool,
+    },
 
-    /// The pragma version of the Solidity contract.
-    #[clap(long, default_value = "^0.8.0")]
-    pub pragma_version: String,
+    /// Convert a snarkjs `.zkey` into the cached `.arkzkey` format for fast mmap loading.
+    ConvertZkey {
+        /// Path to the snarkjs `.zkey`.
+        #[clap(short, long)]
+        input: PathBuf,
+
+        /// Path to write the `.arkzkey` cache to.
+        #[clap(short, long)]
+        output: PathBuf,
+    },
 }
 
-fn main() -> eyre::Result<ExitCode> {
-    let config = Config::parse();
+/// The JSON shape expected by `prepare-proof`: a snarkjs-style proof next to its public inputs.
+#[derive(Debug, serde::Deserialize)]
+struct ProofWithInputs {
+    proof: Proof<Bn254>,
+    public_inputs: Vec<Fr>,
+}
+
+fn generate_verifier(
+    input: PathBuf,
+    output: Option<PathBuf>,
+    pragma_version: String,
+    include_compressed_verify: bool,
+) -> eyre::Result<()> {
     let vk = VerificationKey::<Bn254>::from_reader(
-        File::open(config.input).context("while opening input file")?,
+        File::open(input).context("while opening input file")?,
     )
     .context("while parsing verification-key")?;
 
     let contract = SolidityVerifierContext {
         vk: vk.into(),
         config: SolidityVerifierConfig {
-            pragma_version: config.pragma_version.clone(),
+            pragma_version,
+            include_compressed_verify,
         },
     };
     let rendered = contract.render().unwrap();
-    if let Some(output) = config.output {
+    if let Some(output) = output {
         std::fs::write(output, rendered).context("while writing output")?;
     } else {
         println!("{rendered}")
     }
+    Ok(())
+}
+
+fn prepare_proof(input: PathBuf, output: Option<PathBuf>, compressed: bool) -> eyre::Result<()> {
+    let input: ProofWithInputs = serde_json::from_reader(
+        File::open(input).context("while opening input file")?,
+    )
+    .context("while parsing proof")?;
+    let calldata =
+        taceo_groth16_sol::prepare_calldata(&input.proof.into(), &input.public_inputs, compressed);
+    let hex = format!("0x{}", hex::encode(calldata));
+    if let Some(output) = output {
+        std::fs::write(output, hex).context("while writing output")?;
+    } else {
+        println!("{hex}")
+    }
+    Ok(())
+}
+
+fn convert_zkey(input: PathBuf, output: PathBuf) -> eyre::Result<()> {
+    let zkey = ZKey::<Bn254>::from_reader(File::open(input).context("while opening input file")?)
+        .context("while parsing zkey")?;
+    let ark_zkey: ArkZkey<Bn254> = zkey.into();
+    circom_types::groth16::serialize_arkzkey_to_path(&ark_zkey, output)
+        .context("while writing arkzkey")?;
+    Ok(())
+}
+
+fn main() -> eyre::Result<ExitCode> {
+    let config = Config::parse();
+
+    match config.command {
+        Command::GenerateVerifier {
+            input,
+            output,
+            pragma_version,
+            include_compressed_verify,
+        } => generate_verifier(input, output, pragma_version, include_compressed_verify)?,
+        Command::PrepareProof {
+            input,
+            output,
+            compressed,
+        } => prepare_proof(input, output, compressed)?,
+        Command::ConvertZkey { input, output } => convert_zkey(input, output)?,
+    }
+
     Ok(ExitCode::SUCCESS)
 }
@@ -36,12 +36,25 @@
 //! let compressed_proof = taceo_groth16_sol::prepare_compressed_proof(&proof);
 //! let uncompressed_proof = taceo_groth16_sol::prepare_uncompressed_proof(&proof);
 //! ```
+//!
+//! # Building ready-to-broadcast calldata
+//! [`prepare_calldata`] goes one step further than [`prepare_uncompressed_proof`]/[`prepare_compressed_proof`]
+//! and returns the full ABI-encoded calldata for the generated verifier contract, selector included,
+//! so it can be handed directly to an `eth_call`/`eth_sendTransaction`.
+//!
+//! ```rust,no_run
+//! # fn load_proof() -> ark_groth16::Proof<ark_bn254::Bn254> { todo!() }
+//! # fn load_public_inputs() -> Vec<ark_bn254::Fr> { todo!() }
+//! let proof: ark_groth16::Proof<ark_bn254::Bn254> = load_proof();
+//! let public_inputs = load_public_inputs();
+//! let calldata = taceo_groth16_sol::prepare_calldata(&proof, &public_inputs, false);
+//! ```
 #![deny(missing_docs)]
 
-use alloy_primitives::U256;
-use ark_bn254::{Fq, G1Affine, G2Affine};
+use alloy_primitives::{keccak256, U256};
+use ark_bn254::{Fq, Fr, G1Affine, G2Affine};
 use ark_ec::AffineRepr;
-use ark_ff::Field;
+use ark_ff::{Field, PrimeField};
 use ark_groth16::{Proof, VerifyingKey};
 use askama::Template;
 
@@ -66,22 +79,167 @@ pub struct SolidityVerifierContext {
 ///
 /// Parameters:
 /// - `pragma_version`: The Solidity pragma version to use in the generated contract. Default is "^0.8.0".
+/// - `include_compressed_verify`: Whether to also emit a `verifyCompressedProof` entrypoint. Default is `false`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SolidityVerifierConfig {
     /// The Solidity pragma version to use in the generated contract. Default is "^0.8.0".
     pub pragma_version: String,
+
+    /// Whether to also emit a `verifyCompressedProof(uint256[4],uint256[N])` entrypoint next to
+    /// the default `verifyProof`, matching [`prepare_compressed_proof`]'s calldata layout.
+    ///
+    /// Compressed proofs shrink calldata from 8 to 4 `uint256` words at the cost of decompressing
+    /// the `A`/`C` points in G1 and the `B` point in G2 on-chain (a modular exponentiation per
+    /// coordinate). Whether that tradeoff is worth it depends on the deployment: favor it where
+    /// calldata is the dominant cost (e.g. L1), and leave it off where `expmod` gas would outweigh
+    /// the calldata savings (e.g. an L2 with cheap calldata). Default is `false`.
+    pub include_compressed_verify: bool,
 }
 
 impl Default for SolidityVerifierConfig {
     fn default() -> Self {
         Self {
             pragma_version: "^0.8.0".to_string(),
+            include_compressed_verify: false,
         }
     }
 }
 
+/// Converts a field element into a [`U256`], as expected by the generated verifier contract.
+fn fq_to_u256(f: Fq) -> U256 {
+    U256::from_limbs(f.into_bigint().0)
+}
+
+/// Converts a scalar-field element into a [`U256`], as expected by the generated verifier contract.
+fn fr_to_u256(f: Fr) -> U256 {
+    U256::from_limbs(f.into_bigint().0)
+}
+
+/// Returns whether `y` is the "larger" of the two square roots for its `x` coordinate, i.e.
+/// `y > -y` when both are represented as canonical non-negative field elements. This is the
+/// sign bit used by the point-compression scheme, see <https://2π.com/23/bn254-compression>.
+fn y_is_larger(y: Fq) -> bool {
+    y > -y
+}
+
+/// Compresses a G1 point into a single [`U256`]: the `x` coordinate with the top two bits
+/// of the most-significant limb used as flags (infinity, then sign of `y`).
+fn compress_g1(p: &G1Affine) -> U256 {
+    if p.is_zero() {
+        return U256::from(1) << 254;
+    }
+    let mut x = fq_to_u256(p.x);
+    if y_is_larger(p.y) {
+        x |= U256::from(1) << 255;
+    }
+    x
+}
+
+/// Returns whether `y` (an `Fq2` element) is the "larger" of the two square roots, using the same
+/// lexicographic tie-break as the Solidity decompressor: compare `c1` unless it is zero, in which
+/// case fall back to comparing `c0`. Matching this tie-break exactly is required for
+/// [`compress_g2`]/the contract's `decompressG2` to be reciprocal on every point, including the
+/// ones whose `y.c1` is zero.
+fn g2_y_is_larger(y: ark_bn254::Fq2) -> bool {
+    if y.c1.is_zero() {
+        y_is_larger(y.c0)
+    } else {
+        y_is_larger(y.c1)
+    }
+}
+
+/// Compresses a G2 point into two [`U256`]s (the `x.c1`/`x.c0` limbs), with the top two bits
+/// of `x.c1` used as flags (infinity, then sign of `y`), mirroring [`compress_g1`].
+fn compress_g2(p: &G2Affine) -> [U256; 2] {
+    if p.is_zero() {
+        return [U256::from(1) << 254, U256::ZERO];
+    }
+    let mut x_c1 = fq_to_u256(p.x.c1);
+    if g2_y_is_larger(p.y) {
+        x_c1 |= U256::from(1) << 255;
+    }
+    [x_c1, fq_to_u256(p.x.c0)]
+}
+
+/// Prepares a Groth16 proof for the `verifyProof` entrypoint of the generated verifier contract:
+/// the uncompressed `A`, `B`, `C` points, 8 `uint256` words in total.
+pub fn prepare_uncompressed_proof(proof: &Proof<ark_bn254::Bn254>) -> Vec<U256> {
+    vec![
+        fq_to_u256(proof.a.x),
+        fq_to_u256(proof.a.y),
+        fq_to_u256(proof.b.x.c1),
+        fq_to_u256(proof.b.x.c0),
+        fq_to_u256(proof.b.y.c1),
+        fq_to_u256(proof.b.y.c0),
+        fq_to_u256(proof.c.x),
+        fq_to_u256(proof.c.y),
+    ]
+}
+
+/// Prepares a Groth16 proof for the `verifyCompressedProof` entrypoint of the generated verifier
+/// contract: the compressed `A`, `B`, `C` points, 4 `uint256` words in total.
+pub fn prepare_compressed_proof(proof: &Proof<ark_bn254::Bn254>) -> Vec<U256> {
+    let [b_c1, b_c0] = compress_g2(&proof.b);
+    vec![
+        compress_g1(&proof.a),
+        b_c1,
+        b_c0,
+        compress_g1(&proof.c),
+    ]
+}
+
+/// Computes the 4-byte Solidity function selector for `signature`, i.e. the first four bytes of
+/// `keccak256(signature)`.
+fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Builds the ABI-encoded `uint256[N]` public-input array, reducing each input modulo the scalar
+/// field as the generated verifier contract expects.
+fn encode_public_inputs(public_inputs: &[Fr]) -> Vec<U256> {
+    public_inputs.iter().copied().map(fr_to_u256).collect()
+}
+
+/// Prepares the full, ready-to-broadcast calldata for the generated verifier contract: the 4-byte
+/// function selector followed by the ABI-encoded proof words and public inputs.
+///
+/// Set `compressed` to select between the `verifyCompressedProof(uint256[4],uint256[N])` and
+/// `verifyProof(uint256[8],uint256[N])` entrypoints. The returned bytes can be sent directly as
+/// the `data` field of an `eth_call`/`eth_sendTransaction`.
+pub fn prepare_calldata(
+    proof: &Proof<ark_bn254::Bn254>,
+    public_inputs: &[Fr],
+    compressed: bool,
+) -> Vec<u8> {
+    let n = public_inputs.len();
+    let (sig, proof_words) = if compressed {
+        (
+            format!("verifyCompressedProof(uint256[4],uint256[{n}])"),
+            prepare_compressed_proof(proof),
+        )
+    } else {
+        (
+            format!("verifyProof(uint256[8],uint256[{n}])"),
+            prepare_uncompressed_proof(proof),
+        )
+    };
+
+    let mut calldata = selector(&sig).to_vec();
+    for word in proof_words.iter().chain(encode_public_inputs(public_inputs).iter()) {
+        calldata.extend_from_slice(&word.to_be_bytes::<32>());
+    }
+    calldata
+}
+
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::{keccak256, U256};
+    use ark_bn254::{Bn254, Fq2, Fr, G1Affine, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{Field, UniformRand};
+    use ark_groth16::Proof;
+    use ark_std::test_rng;
     use askama::Template;
     use taceo_circom_types::groth16::VerificationKey;
 
@@ -102,4 +260,103 @@ mod tests {
         let rendered = format!("{}\n", rendered);
         assert_eq!(rendered, TEST_GNARK_OUTPUT);
     }
+
+    /// Reconstructs `y` from a compressed G1 point's sign bit the same way
+    /// `decompressG1`/`sqrtMod` do on-chain, but using `ark_bn254::Fq::sqrt()` instead of the
+    /// `modexp` precompile, and asserts it round-trips to the original point.
+    #[test]
+    fn compress_g1_sign_round_trips() {
+        let mut rng = test_rng();
+        let points = [
+            G1Affine::generator(),
+            (G1Affine::generator() * Fr::rand(&mut rng)).into_affine(),
+            (G1Affine::generator() * Fr::rand(&mut rng)).into_affine(),
+        ];
+        for p in points {
+            let compressed = super::compress_g1(&p);
+            let sign_bit = (compressed >> 255) & U256::from(1) == U256::from(1);
+
+            let y_squared = p.y * p.y;
+            let mut y = y_squared.sqrt().expect("y^2 must have a square root");
+            if super::y_is_larger(y) != sign_bit {
+                y = -y;
+            }
+            assert_eq!(y, p.y);
+        }
+    }
+
+    /// Same as [`compress_g1_sign_round_trips`], but for G2: reconstructs `y` via
+    /// `ark_bn254::Fq2::sqrt()` and the `c1`-unless-zero tie-break that
+    /// `decompressG2`/`sqrtFp2` implement on-chain, and checks it agrees with [`super::compress_g2`]
+    /// for sampled points, including the `y.c1 == 0` edge case the tie-break exists for.
+    #[test]
+    fn compress_g2_sign_round_trips() {
+        let mut rng = test_rng();
+        let mut points = vec![
+            G2Affine::generator(),
+            (G2Affine::generator() * Fr::rand(&mut rng)).into_affine(),
+            (G2Affine::generator() * Fr::rand(&mut rng)).into_affine(),
+        ];
+        // A synthetic y with c1 == 0 to exercise the tie-break fallback to c0, even though such a
+        // point need not lie on the actual curve: compress_g2/g2_y_is_larger operate on the
+        // Fq2 coordinates alone and don't validate curve membership.
+        let mut edge_case = points[0];
+        edge_case.y.c1 = ark_bn254::Fq::from(0u64);
+        points.push(edge_case);
+
+        for p in points {
+            let [x_c1_packed, _x_c0] = super::compress_g2(&p);
+            let sign_bit = (x_c1_packed >> 255) & U256::from(1) == U256::from(1);
+
+            let y_squared = p.y * p.y;
+            let mut y: Fq2 = y_squared.sqrt().expect("y^2 must have a square root");
+            let larger = if y.c1.is_zero() {
+                super::y_is_larger(y.c0)
+            } else {
+                super::y_is_larger(y.c1)
+            };
+            if larger != sign_bit {
+                y = -y;
+            }
+            assert_eq!(y, p.y);
+        }
+    }
+
+    /// The 4-byte selector must match `keccak256("verifyProof(uint256[8],uint256[1])")[..4]`, the
+    /// one ark-groth16/the generated contract actually expose on-chain.
+    #[test]
+    fn selector_matches_keccak256_of_signature() {
+        let expected = &keccak256("verifyProof(uint256[8],uint256[1])")[..4];
+        assert_eq!(super::selector("verifyProof(uint256[8],uint256[1])"), expected);
+    }
+
+    /// Pins the exact byte layout of [`super::prepare_calldata`]: 4-byte selector, then one
+    /// big-endian `uint256` per proof word, then one per public input — so a future refactor of
+    /// word ordering regresses a visible assertion instead of silently breaking deployed callers.
+    #[test]
+    fn prepare_calldata_matches_documented_layout() {
+        let proof = Proof::<Bn254> {
+            a: G1Affine::generator(),
+            b: G2Affine::generator(),
+            c: G1Affine::generator(),
+        };
+        let public_inputs = vec![Fr::from(42u64)];
+
+        let calldata = super::prepare_calldata(&proof, &public_inputs, false);
+
+        let expected_selector = &keccak256("verifyProof(uint256[8],uint256[1])")[..4];
+        assert_eq!(&calldata[..4], expected_selector);
+
+        let proof_words = super::prepare_uncompressed_proof(&proof);
+        assert_eq!(calldata.len(), 4 + 32 * (proof_words.len() + public_inputs.len()));
+        for (i, word) in proof_words.iter().enumerate() {
+            let start = 4 + i * 32;
+            assert_eq!(&calldata[start..start + 32], &word.to_be_bytes::<32>());
+        }
+        let public_start = 4 + 32 * proof_words.len();
+        assert_eq!(
+            &calldata[public_start..public_start + 32],
+            &super::fr_to_u256(public_inputs[0]).to_be_bytes::<32>()
+        );
+    }
 }